@@ -0,0 +1,17 @@
+# SPDX-FileCopyrightText: 2026 agent <agent@local>
+# SPDX-License-Identifier: BSD-3-Clause
+
+# The keyring is copied here by src/mimetreeparser/tests/gnupg_home
+set(GNUPGHOME ${CMAKE_BINARY_DIR}/src/mimetreeparser/tests/gnupg_home)
+add_definitions(-DGNUPGHOME="${GNUPGHOME}")
+
+# Registers an already created test executable so that it runs against the
+# test keyring instead of the user's one.
+function(add_gpg_crypto_test _target _testname)
+    add_test(NAME ${_testname} COMMAND $<TARGET_FILE:${_target}>)
+    set_tests_properties(${_testname} PROPERTIES
+        ENVIRONMENT "GNUPGHOME=${GNUPGHOME};LC_ALL=C"
+        # can't be parallelized due to gpg-agent
+        RUN_SERIAL TRUE
+    )
+endfunction()
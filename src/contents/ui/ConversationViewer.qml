@@ -27,6 +27,14 @@ Kirigami.ScrollablePage {
             text: i18n("Move to trash")
             iconName: "albumfolder-user-trash"
             // TODO implement move to trash
+        },
+        Kirigami.Action {
+            text: i18n("View Source")
+            iconName: "view-source"
+            enabled: mailViewer.loaded
+            onTriggered: applicationWindow().pageStack.layers.push(Qt.resolvedUrl('MessageSourcePage.qml'), {
+                source: mailViewer.rawContent(),
+            })
         }
     ]
     
@@ -40,7 +48,8 @@ Kirigami.ScrollablePage {
             Layout.bottomMargin: Kirigami.Units.gridUnit
             Layout.fillWidth: true
             
-            text: props.title
+            // prefer the subject from the parsed message, which may be a protected header
+            text: mailViewer.parsedSubject.length > 0 ? mailViewer.parsedSubject : props.title
             maximumLineCount: 2
             wrapMode: Text.Wrap
             elide: Text.ElideRIght
@@ -50,6 +59,7 @@ Kirigami.ScrollablePage {
         
         // TODO use repeater to see the full conversation
         MailViewer {
+            id: mailViewer
             Layout.bottomMargin: Kirigami.Units.gridUnit * 2
             Layout.fillWidth: true
 
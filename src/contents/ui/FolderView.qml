@@ -14,6 +14,15 @@ Kirigami.ScrollablePage {
     id: folderView
     title: MailManager.selectedFolderName
     
+    contextualActions: [
+        Kirigami.Action {
+            text: i18n("Mark All as Read")
+            iconName: "mail-mark-read"
+            enabled: MailManager.selectedFolderName !== ""
+            onTriggered: MailManager.markSelectedFolderAsRead()
+        }
+    ]
+    
     Component {
         id: contextMenu
         QQC2.Menu {
@@ -91,7 +100,8 @@ Kirigami.ScrollablePage {
             showSeparator: model.index !== folderView.count - 1
             
             datetime: model.datetime.toLocaleTimeString(Qt.locale(), Locale.ShortFormat) // TODO this is not showing date !
-            author: model.from
+            author: model.fromName
+            authorEmail: model.fromEmail
             title: model.title
             contentPreview: "This is a test of the message content........." // model.content
             
@@ -15,6 +15,7 @@ Kirigami.AbstractListItem {
     
     property string datetime
     property string author
+    property string authorEmail
     property string title
     property string contentPreview
     
@@ -79,10 +80,9 @@ Kirigami.AbstractListItem {
             anchors.right: parent.right
             
             Kirigami.Avatar {
-                // Euristic to extract name from "Name <email>" pattern
-                name: author.replace(/<.*>/, '').replace(/\(.*\)/, '')
-                // Extract and use email address as unique identifier for image provider
-                source: 'image://contact/' + new RegExp("<(.*)>").exec(author)[1] ?? ''
+                name: root.author
+                // Use email address as unique identifier for image provider
+                source: root.authorEmail.length > 0 ? 'image://contact/' + root.authorEmail : ''
                 Layout.rightMargin: Kirigami.Units.largeSpacing
                 sourceSize.width: Kirigami.Units.gridUnit + Kirigami.Units.largeSpacing * 2
                 sourceSize.height: Kirigami.Units.gridUnit + Kirigami.Units.largeSpacing * 2
@@ -21,6 +21,12 @@ QQC2.Page {
     property string sender
     property string to
     property date dateTime
+    readonly property string parsedSubject: mailPartView.subject
+    readonly property bool loaded: mailPartView.loaded
+
+    function rawContent() {
+        return mailPartView.rawContent();
+    }
 
     Kirigami.Theme.colorSet: Kirigami.Theme.View
     Kirigami.Theme.inherit: false
@@ -140,6 +146,7 @@ QQC2.Page {
                 delegate: AttachmentDelegate {
                     name: model.name
                     type: model.type
+                    dangerous: model.dangerous
                     icon.name: model.iconName
 
                     clip: true
@@ -147,7 +154,15 @@ QQC2.Page {
                     actionIcon: 'download'
                     actionTooltip: i18n("Save attachment")
                     onExecute: mailPartView.attachmentModel.saveAttachmentToDisk(mailPartView.attachmentModel.index(index, 0))
-                    onClicked: mailPartView.attachmentModel.openAttachment(mailPartView.attachmentModel.index(index, 0))
+                    onClicked: {
+                        if (model.dangerous) {
+                            applicationWindow().showPassiveNotification(i18n("%1 was not opened because it may contain executable code. You can still save it.", model.name));
+                            return;
+                        }
+                        if (!mailPartView.attachmentModel.openAttachment(mailPartView.attachmentModel.index(index, 0))) {
+                            applicationWindow().showPassiveNotification(i18n("Failed to open %1.", model.name));
+                        }
+                    }
                     onPublicKeyImport: mailPartView.attachmentModel.importPublicKey(mailPartView.attachmentModel.index(index, 0))
                 }
             }
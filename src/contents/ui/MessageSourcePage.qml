@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-License-Identifier: GPL-2.0-or-later
+
+import QtQuick 2.15
+import QtQuick.Controls 2.15 as QQC2
+
+import org.kde.kirigami 2.19 as Kirigami
+import org.kde.raven 1.0
+
+Kirigami.ScrollablePage {
+    id: root
+    title: i18n("Message Source")
+
+    property string source
+
+    actions.main: Kirigami.Action {
+        text: i18n("Copy")
+        iconName: "edit-copy"
+        onTriggered: {
+            Raven.copyToClipboard(root.source);
+            applicationWindow().showPassiveNotification(i18n("Message source copied to the clipboard."));
+        }
+    }
+
+    // One delegate per line, so only the visible part of large messages
+    // (e.g. base64 encoded attachments) is laid out
+    ListView {
+        model: root.source.split('\n')
+        reuseItems: true
+
+        delegate: TextEdit {
+            width: ListView.view.width
+            text: modelData
+            textFormat: TextEdit.PlainText
+            wrapMode: TextEdit.WrapAnywhere
+            readOnly: true
+            selectByMouse: true
+            font.family: "monospace"
+            color: Kirigami.Theme.textColor
+            selectionColor: Kirigami.Theme.highlightColor
+            selectedTextColor: Kirigami.Theme.highlightedTextColor
+        }
+    }
+}
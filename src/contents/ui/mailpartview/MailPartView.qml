@@ -19,6 +19,13 @@ ListView {
     property alias searchString: visualModel.searchString
     property alias autoLoadImages: visualModel.autoLoadImages
     property var attachmentModel: messageParser.attachments
+    readonly property string subject: messageParser.subject
+    readonly property bool loaded: messageParser.loaded
+
+    // not a property, so the source is only built when it is actually requested
+    function rawContent() {
+        return messageParser.rawContent;
+    }
 
     interactive: false
     spacing: Kirigami.Units.smallSpacing
@@ -13,6 +13,7 @@ QQC2.AbstractButton {
 
     property string name
     property string type
+    property bool dangerous: false
     property alias actionIcon: actionButton.icon.name
     property alias actionTooltip: actionButtonTooltip.text
     signal execute;
@@ -50,6 +51,17 @@ QQC2.AbstractButton {
         QQC2.Label {
             text: root.name
         }
+        Kirigami.Icon {
+            visible: root.dangerous
+            Layout.preferredHeight: Kirigami.Units.iconSizes.small
+            Layout.preferredWidth: Kirigami.Units.iconSizes.small
+            source: 'dialog-warning'
+            QQC2.ToolTip.visible: dangerousHover.hovered
+            QQC2.ToolTip.text: i18n("This attachment may contain executable code and will not be opened directly")
+            HoverHandler {
+                id: dangerousHover
+            }
+        }
         QQC2.ToolButton {
             visible: root.type === "application/pgp-keys"
             icon.name: 'gpg'
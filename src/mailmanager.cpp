@@ -12,7 +12,9 @@
 #include <Akonadi/EntityMimeTypeFilterModel>
 #include <Akonadi/EntityTreeModel>
 #include <Akonadi/ItemFetchScope>
+#include <Akonadi/MarkAsCommand>
 #include <Akonadi/MessageModel>
+#include <Akonadi/MessageStatus>
 #include <Akonadi/Monitor>
 #include <Akonadi/SelectionProxyModel>
 #include <Akonadi/ServerManager>
@@ -124,6 +126,26 @@ void MailManager::loadMailCollection(const QModelIndex &modelIndex)
     m_collectionSelectionModel->select(modelIndex, QItemSelectionModel::ClearAndSelect);
 }
 
+void MailManager::markSelectedFolderAsRead()
+{
+    const auto indexes = m_collectionSelectionModel->selectedIndexes();
+    if (indexes.isEmpty()) {
+        return;
+    }
+
+    const auto collection = indexes.first().data(Akonadi::EntityTreeModel::CollectionRole).value<Akonadi::Collection>();
+    if (!collection.isValid()) {
+        return;
+    }
+
+    Akonadi::MessageStatus status;
+    status.setRead(true);
+
+    // MarkAsCommand deletes itself once all items are modified
+    auto command = new Akonadi::MarkAsCommand(status, Akonadi::Collection::List{collection}, false, false, this);
+    command->execute();
+}
+
 bool MailManager::loading() const
 {
     return m_loading;
@@ -33,6 +33,7 @@ public:
     QString selectedFolderName() const;
 
     Q_INVOKABLE void loadMailCollection(const QModelIndex &index);
+    Q_INVOKABLE void markSelectedFolderAsRead();
 
 Q_SIGNALS:
     void loadingChanged();
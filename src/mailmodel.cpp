@@ -26,6 +26,8 @@ QHash<int, QByteArray> MailModel::roleNames() const
         {DateTimeRole, QByteArrayLiteral("datetime")},
         {SenderRole, QByteArrayLiteral("sender")},
         {FromRole, QByteArrayLiteral("from")},
+        {FromNameRole, QByteArrayLiteral("fromName")},
+        {FromEmailRole, QByteArrayLiteral("fromEmail")},
         {ToRole, QByteArrayLiteral("to")},
         {StatusRole, QByteArrayLiteral("status")},
         {FavoriteRole, QByteArrayLiteral("favorite")},
@@ -72,6 +74,22 @@ QVariant MailModel::data(const QModelIndex &index, int role) const
         } else {
             return QString();
         }
+    case FromNameRole:
+        if (mail->from() && !mail->from()->mailboxes().isEmpty()) {
+            // Prefer the display name, fall back to the bare address
+            const auto mailbox = mail->from()->mailboxes().constFirst();
+            return mailbox.hasName() ? mailbox.name() : QString::fromUtf8(mailbox.address());
+        } else if (mail->from()) {
+            return mail->from()->asUnicodeString();
+        } else {
+            return unknown;
+        }
+    case FromEmailRole:
+        if (mail->from() && !mail->from()->mailboxes().isEmpty()) {
+            return QString::fromUtf8(mail->from()->mailboxes().constFirst().address());
+        } else {
+            return QString();
+        }
     case SenderRole:
         if (mail->sender()) {
             return mail->sender()->asUnicodeString();
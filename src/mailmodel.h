@@ -21,6 +21,8 @@ public:
         TitleRole = Qt::UserRole + 1,
         SenderRole,
         FromRole,
+        FromNameRole,
+        FromEmailRole,
         ToRole,
         TextColorRole,
         DateRole,
@@ -14,6 +14,7 @@
 #include <QDesktopServices>
 #include <QDir>
 #include <QFile>
+#include <QFileInfo>
 #include <QMimeDatabase>
 #include <QStandardPaths>
 #include <QUrl>
@@ -76,6 +77,7 @@ QHash<int, QByteArray> AttachmentModel::roleNames() const
         {IconRole, QByteArrayLiteral("iconName")},
         {IsEncryptedRole, QByteArrayLiteral("encrypted")},
         {IsSignedRole, QByteArrayLiteral("signed")},
+        {IsDangerousRole, QByteArrayLiteral("dangerous")},
     };
 }
 
@@ -125,6 +127,8 @@ QVariant AttachmentModel::data(const QModelIndex &index, int role) const
             return part->encryptions().size() > 0;
         case IsSignedRole:
             return part->signatures().size() > 0;
+        case IsDangerousRole:
+            return isDangerous(mimetype, part->filename());
         }
     }
     return QVariant();
@@ -197,6 +201,10 @@ bool AttachmentModel::saveAttachmentToDisk(const QModelIndex &index)
 
 bool AttachmentModel::openAttachment(const QModelIndex &index)
 {
+    if (data(index, IsDangerousRole).toBool()) {
+        qWarning() << "Refusing to open potentially dangerous attachment:" << data(index, NameRole).toString();
+        return false;
+    }
     auto downloadDir = QStandardPaths::writableLocation(QStandardPaths::TempLocation) + QStringLiteral("/kalendar/");
     QDir{}.mkpath(downloadDir);
     const auto filePath = internalSaveAttachmentToDisk(index, downloadDir, true);
@@ -235,6 +243,53 @@ bool AttachmentModel::importPublicKey(const QModelIndex &index)
     return success;
 }
 
+bool AttachmentModel::isDangerous(const QMimeType &mimetype, const QString &filename)
+{
+    // Both the old and new canonical names are listed where shared-mime-info
+    // renamed a type, so the check does not depend on its version
+    static const QStringList dangerousMimeTypes = {
+        QStringLiteral("application/x-executable"),
+        QStringLiteral("application/x-sharedlib"),
+        QStringLiteral("application/x-pie-executable"),
+        QStringLiteral("application/x-ms-dos-executable"),
+        QStringLiteral("application/x-msdownload"),
+        QStringLiteral("application/x-msi"),
+        QStringLiteral("application/x-ms-shortcut"),
+        QStringLiteral("application/x-shellscript"),
+        QStringLiteral("application/x-desktop"),
+        QStringLiteral("application/javascript"),
+        QStringLiteral("text/javascript"),
+        QStringLiteral("application/vnd.android.package-archive"),
+    };
+    static const QStringList dangerousSuffixes = {
+        QStringLiteral("exe"), QStringLiteral("com"), QStringLiteral("scr"), QStringLiteral("pif"), QStringLiteral("msi"), QStringLiteral("lnk"),
+        QStringLiteral("hta"), QStringLiteral("reg"), QStringLiteral("cpl"), QStringLiteral("msc"), QStringLiteral("bat"), QStringLiteral("cmd"),
+        QStringLiteral("js"), QStringLiteral("jse"), QStringLiteral("vbs"), QStringLiteral("vbe"), QStringLiteral("wsf"), QStringLiteral("ps1"),
+        QStringLiteral("jar"), QStringLiteral("apk"), QStringLiteral("sh"), QStringLiteral("desktop"),
+    };
+
+    // Match the type and its aliases exactly: inherits() would also flag JSON,
+    // which shared-mime-info declares a subclass of application/javascript.
+    if (dangerousMimeTypes.contains(mimetype.name())) {
+        return true;
+    }
+    const auto aliases = mimetype.aliases();
+    for (const auto &alias : aliases) {
+        if (dangerousMimeTypes.contains(alias)) {
+            return true;
+        }
+    }
+
+    // Windows ignores trailing dots and spaces, so "setup.exe. " still runs.
+    // Only the last suffix counts, which flags "invoice.pdf.exe" but not "setup.exe.pdf".
+    auto name = filename;
+    while (name.endsWith(QLatin1Char('.')) || name.endsWith(QLatin1Char(' '))) {
+        name.chop(1);
+    }
+    const auto suffix = QFileInfo(name.trimmed()).suffix().toLower();
+    return dangerousSuffixes.contains(suffix);
+}
+
 QModelIndex AttachmentModel::parent(const QModelIndex &) const
 {
     return {};
@@ -7,6 +7,7 @@
 #include <QObject>
 
 #include <QAbstractItemModel>
+#include <QMimeType>
 #include <QModelIndex>
 
 #include <memory>
@@ -25,7 +26,7 @@ public:
     ~AttachmentModel();
 
 public:
-    enum Roles { TypeRole = Qt::UserRole + 1, IconRole, NameRole, SizeRole, IsEncryptedRole, IsSignedRole };
+    enum Roles { TypeRole = Qt::UserRole + 1, IconRole, NameRole, SizeRole, IsEncryptedRole, IsSignedRole, IsDangerousRole };
 
     QHash<int, QByteArray> roleNames() const Q_DECL_OVERRIDE;
     QModelIndex index(int row, int column, const QModelIndex &parent = QModelIndex()) const Q_DECL_OVERRIDE;
@@ -39,6 +40,12 @@ public:
 
     Q_INVOKABLE bool importPublicKey(const QModelIndex &index);
 
+    /**
+     * Whether an attachment with this type and file name would be executed
+     * rather than displayed when opened.
+     */
+    static bool isDangerous(const QMimeType &mimetype, const QString &filename);
+
 private:
     std::unique_ptr<AttachmentModelPrivate> d;
 };
@@ -8,6 +8,7 @@
 #include <Akonadi/ItemFetchJob>
 #include <Akonadi/ItemFetchScope>
 #include <QElapsedTimer>
+#include <QTextCodec>
 
 #include "async.h"
 #include "attachmentmodel.h"
@@ -17,6 +18,7 @@ class MessagePartPrivate
 {
 public:
     std::shared_ptr<MimeTreeParser::ObjectTreeParser> mParser;
+    KMime::Message::Ptr mMessage;
 };
 
 MessageParser::MessageParser(QObject *parent)
@@ -47,25 +49,58 @@ void MessageParser::setItem(const Akonadi::Item &item)
         }
         const auto item = items.at(0);
         if (item.hasPayload<KMime::Message::Ptr>()) {
-            const auto message = item.payload<KMime::Message::Ptr>();
-            QElapsedTimer time;
-            time.start();
-            auto parser = std::make_shared<MimeTreeParser::ObjectTreeParser>();
-            parser->parseObjectTree(message.data());
-            qDebug() << "Message parsing took: " << time.elapsed();
-            parser->decryptParts();
-            qDebug() << "Message parsing and decryption/verification: " << time.elapsed();
-            d->mParser = parser;
-            Q_EMIT htmlChanged();
+            setMessage(item.payload<KMime::Message::Ptr>());
         } else {
             qWarning() << "This is not a mime item.";
         }
     });
 }
 
+void MessageParser::setMessage(const KMime::Message::Ptr &message)
+{
+    QElapsedTimer time;
+    time.start();
+    auto parser = std::make_shared<MimeTreeParser::ObjectTreeParser>();
+    parser->parseObjectTree(message.data());
+    qDebug() << "Message parsing took: " << time.elapsed();
+    parser->decryptParts();
+    qDebug() << "Message parsing and decryption/verification: " << time.elapsed();
+    d->mParser = parser;
+    d->mMessage = message;
+    Q_EMIT htmlChanged();
+}
+
 QString MessageParser::rawContent() const
 {
-    return mRawContent;
+    if (!d->mMessage) {
+        return QString();
+    }
+    const auto content = d->mMessage->encodedContent();
+    // 8bit bodies and RFC 6532 headers are mostly UTF-8. Anything else falls
+    // back to Latin-1, which maps every byte to one character instead of
+    // replacing it.
+    QTextCodec::ConverterState state;
+    const auto source = QTextCodec::codecForName("UTF-8")->toUnicode(content.constData(), content.size(), &state);
+    if (state.invalidChars == 0) {
+        return source;
+    }
+    return QString::fromLatin1(content);
+}
+
+QVariantList MessageParser::headers() const
+{
+    if (!d->mMessage) {
+        return {};
+    }
+    QVariantList result;
+    const auto headers = d->mMessage->headers();
+    for (const auto header : headers) {
+        result.append(QVariantMap{
+            {QStringLiteral("name"), QString::fromLatin1(header->type())},
+            {QStringLiteral("value"), header->asUnicodeString()},
+        });
+    }
+    return result;
 }
 
 bool MessageParser::loaded() const
@@ -81,6 +116,23 @@ QString MessageParser::structureAsString() const
     return d->mParser->structureAsString();
 }
 
+QString MessageParser::subject() const
+{
+    if (!d->mParser) {
+        return QString();
+    }
+    // Content parts look up headers through their parents, so this returns the
+    // protected (memoryhole) subject of an encrypted message when there is one
+    const auto parts = d->mParser->collectContentParts();
+    if (parts.isEmpty()) {
+        return QString();
+    }
+    if (const auto header = parts.first()->header("subject")) {
+        return header->asUnicodeString();
+    }
+    return QString();
+}
+
 QAbstractItemModel *MessageParser::parts() const
 {
     if (!d->mParser) {
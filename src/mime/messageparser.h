@@ -5,8 +5,10 @@
 #include <QObject>
 #include <QString>
 #include <QStringList>
+#include <QVariantList>
 
 #include <Akonadi/Item>
+#include <KMime/Message>
 #include <QAbstractItemModel>
 #include <QModelIndex>
 
@@ -21,7 +23,9 @@ class MessageParser : public QObject
     Q_PROPERTY(QAbstractItemModel *parts READ parts NOTIFY htmlChanged)
     Q_PROPERTY(QAbstractItemModel *attachments READ attachments NOTIFY htmlChanged)
     Q_PROPERTY(QString rawContent READ rawContent NOTIFY htmlChanged)
+    Q_PROPERTY(QVariantList headers READ headers NOTIFY htmlChanged)
     Q_PROPERTY(QString structureAsString READ structureAsString NOTIFY htmlChanged)
+    Q_PROPERTY(QString subject READ subject NOTIFY htmlChanged)
     Q_PROPERTY(bool loaded READ loaded NOTIFY htmlChanged)
 
 public:
@@ -30,10 +34,13 @@ public:
 
     Akonadi::Item item() const;
     void setItem(const Akonadi::Item &item);
+    void setMessage(const KMime::Message::Ptr &message);
     QAbstractItemModel *parts() const;
     QAbstractItemModel *attachments() const;
     QString rawContent() const;
+    QVariantList headers() const;
     QString structureAsString() const;
+    QString subject() const;
     bool loaded() const;
 
 Q_SIGNALS:
@@ -41,5 +48,4 @@ Q_SIGNALS:
 
 private:
     std::unique_ptr<MessagePartPrivate> d;
-    QString mRawContent;
 };
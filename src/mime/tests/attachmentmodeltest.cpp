@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-License-Identifier: LGPL-2.0-or-later
+
+#include <QMimeDatabase>
+#include <QTest>
+
+#include "../attachmentmodel.h"
+
+class AttachmentModelTest : public QObject
+{
+    Q_OBJECT
+
+private Q_SLOTS:
+    void testIsDangerous_data()
+    {
+        QTest::addColumn<QString>("mimeType");
+        QTest::addColumn<QString>("filename");
+        QTest::addColumn<bool>("dangerous");
+
+        const auto octetStream = QStringLiteral("application/octet-stream");
+        QTest::newRow("pdf") << QStringLiteral("application/pdf") << QStringLiteral("invoice.pdf") << false;
+        QTest::newRow("image") << QStringLiteral("image/png") << QStringLiteral("photo.png") << false;
+        QTest::newRow("no name") << octetStream << QString() << false;
+        QTest::newRow("no suffix") << octetStream << QStringLiteral("README") << false;
+        QTest::newRow("exe") << octetStream << QStringLiteral("setup.exe") << true;
+        QTest::newRow("upper-case suffix") << octetStream << QStringLiteral("SETUP.EXE") << true;
+        QTest::newRow("mixed-case suffix") << octetStream << QStringLiteral("Script.Vbs") << true;
+        QTest::newRow("double extension") << QStringLiteral("application/pdf") << QStringLiteral("invoice.pdf.exe") << true;
+        QTest::newRow("double extension, harmless last") << QStringLiteral("application/pdf") << QStringLiteral("setup.exe.pdf") << false;
+        QTest::newRow("trailing dot") << octetStream << QStringLiteral("setup.exe.") << true;
+        QTest::newRow("trailing space") << octetStream << QStringLiteral("setup.exe ") << true;
+        QTest::newRow("lnk") << octetStream << QStringLiteral("Documents.lnk") << true;
+        QTest::newRow("hta") << octetStream << QStringLiteral("update.hta") << true;
+        QTest::newRow("reg") << octetStream << QStringLiteral("fix.reg") << true;
+        QTest::newRow("cpl") << octetStream << QStringLiteral("panel.cpl") << true;
+        QTest::newRow("msc") << octetStream << QStringLiteral("console.msc") << true;
+        QTest::newRow("apk") << octetStream << QStringLiteral("app.apk") << true;
+        QTest::newRow("javascript type, harmless name") << QStringLiteral("application/javascript") << QStringLiteral("notes.txt") << true;
+        QTest::newRow("javascript alias, harmless name") << QStringLiteral("application/x-javascript") << QStringLiteral("notes.txt") << true;
+        QTest::newRow("shell script type, harmless name") << QStringLiteral("application/x-shellscript") << QStringLiteral("notes.txt") << true;
+        QTest::newRow("executable type, harmless name") << QStringLiteral("application/x-executable") << QStringLiteral("photo.jpg") << true;
+        QTest::newRow("shared library type, harmless name") << QStringLiteral("application/x-sharedlib") << QStringLiteral("photo.jpg") << true;
+        QTest::newRow("json") << QStringLiteral("application/json") << QStringLiteral("data.json") << false;
+        QTest::newRow("geojson") << QStringLiteral("application/geo+json") << QStringLiteral("map.geojson") << false;
+    }
+
+    void testIsDangerous()
+    {
+        QFETCH(QString, mimeType);
+        QFETCH(QString, filename);
+        QFETCH(bool, dangerous);
+
+        QMimeDatabase mimeDb;
+        QCOMPARE(AttachmentModel::isDangerous(mimeDb.mimeTypeForName(mimeType), filename), dangerous);
+    }
+};
+
+QTEST_GUILESS_MAIN(AttachmentModelTest)
+#include "attachmentmodeltest.moc"
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-License-Identifier: LGPL-2.0-or-later
+
+#include <QFile>
+#include <QTest>
+
+#include "../messageparser.h"
+
+static KMime::Message::Ptr readMail(const QString &mailFile)
+{
+    QFile file(QLatin1String(MAIL_DATA_DIR) + QLatin1Char('/') + mailFile);
+    file.open(QIODevice::ReadOnly);
+    Q_ASSERT(file.isOpen());
+    auto msg = KMime::Message::Ptr::create();
+    msg->setContent(file.readAll());
+    msg->parse();
+    return msg;
+}
+
+class MessageParserTest : public QObject
+{
+    Q_OBJECT
+
+private Q_SLOTS:
+    void testRawContentKeeps8bitBytes()
+    {
+        const QByteArray body = "Gr\xfc\xdf" "e aus K\xf6ln\n";
+        auto msg = KMime::Message::Ptr::create();
+        msg->setContent(QByteArray("From: sender@example.com\n"
+                                   "Subject: 8bit latin1\n"
+                                   "Content-Type: text/plain; charset=iso-8859-1\n"
+                                   "Content-Transfer-Encoding: 8bit\n"
+                                   "\n")
+                        + body);
+        msg->parse();
+
+        MessageParser parser;
+        parser.setMessage(msg);
+        const auto source = parser.rawContent();
+        QVERIFY(!source.contains(QChar::ReplacementCharacter));
+        QVERIFY(source.toLatin1().contains(body));
+        QCOMPARE(source.toLatin1(), msg->encodedContent());
+    }
+
+    void testRawContentDecodesUtf8()
+    {
+        const QByteArray body = "Gr\xc3\xbc\xc3\x9f" "e aus K\xc3\xb6ln\n";
+        auto msg = KMime::Message::Ptr::create();
+        msg->setContent(QByteArray("From: sender@example.com\n"
+                                   "Subject: 8bit utf-8\n"
+                                   "Content-Type: text/plain; charset=utf-8\n"
+                                   "Content-Transfer-Encoding: 8bit\n"
+                                   "\n")
+                        + body);
+        msg->parse();
+
+        MessageParser parser;
+        parser.setMessage(msg);
+        const auto source = parser.rawContent();
+        QVERIFY(source.contains(QStringLiteral("Gr\u00FC\u00DFe aus K\u00F6ln")));
+        QCOMPARE(source.toUtf8(), msg->encodedContent());
+    }
+
+    void testRawContentWithoutMessage()
+    {
+        MessageParser parser;
+        QVERIFY(parser.rawContent().isEmpty());
+        QVERIFY(parser.headers().isEmpty());
+    }
+
+    void testHeaders()
+    {
+        MessageParser parser;
+        parser.setMessage(readMail(QLatin1String("8bitencoded.mbox")));
+        const auto headers = parser.headers();
+
+        QStringList received;
+        QString subject;
+        QString from;
+        for (const auto &header : headers) {
+            const auto map = header.toMap();
+            const auto name = map.value(QStringLiteral("name")).toString();
+            if (name == QLatin1String("Received")) {
+                received << map.value(QStringLiteral("value")).toString();
+            } else if (name == QLatin1String("Subject")) {
+                subject = map.value(QStringLiteral("value")).toString();
+            } else if (name == QLatin1String("From")) {
+                from = map.value(QStringLiteral("value")).toString();
+            }
+        }
+
+        // Headers keep their order in the message
+        QCOMPARE(headers.first().toMap().value(QStringLiteral("name")).toString(), QStringLiteral("Return-Path"));
+
+        // Repeated headers are all kept, in order, top (most recent hop) first
+        QCOMPARE(received.size(), 7);
+        QVERIFY(received.at(0).startsWith(QLatin1String("from imapb010.mykolab.com")));
+        QVERIFY(received.at(1).startsWith(QLatin1String("from int-mx002.mykolab.com")));
+        QVERIFY(received.at(2).startsWith(QLatin1String("from mx.kolabnow.com")));
+        QVERIFY(received.at(3).startsWith(QLatin1String("from mail-qk0-f179.google.com")));
+        QVERIFY(received.at(4).startsWith(QLatin1String("by mail-qk0-f179.google.com")));
+        QVERIFY(received.at(5).startsWith(QLatin1String("by 10.140.23.148")));
+        QVERIFY(received.at(6).startsWith(QLatin1String("from mail142.atl61.mcsv.net")));
+
+        // RFC 2047 encoded words are decoded
+        QCOMPARE(subject, QStringLiteral("Now I Know:\u00A0Why Pisa\u2019s Tower Leans"));
+        QCOMPARE(from, QStringLiteral("Dan Lewis <dan@nowiknow.com>"));
+    }
+
+    void testSubject()
+    {
+        MessageParser parser;
+        QVERIFY(parser.subject().isEmpty());
+
+        parser.setMessage(readMail(QLatin1String("plaintext.mbox")));
+        QCOMPARE(parser.subject(), QStringLiteral("A random subject with alternative contenttype"));
+
+        // The protected subject inside the encrypted part wins over the outer one
+        parser.setMessage(readMail(QLatin1String("openpgp-encrypted-memoryhole.mbox")));
+        QCOMPARE(parser.subject(), QStringLiteral("hidden subject"));
+    }
+};
+
+QTEST_MAIN(MessageParserTest)
+#include "messageparsertest.moc"
@@ -4,6 +4,8 @@
 #include <objecttreeparser.h>
 
 #include <QDebug>
+#include <QDir>
+#include <QRandomGenerator>
 #include <QTest>
 #include <QTimeZone>
 
@@ -769,6 +771,49 @@ private Q_SLOTS:
         QVERIFY(bool(part));
         QCOMPARE(part->text(), QStringLiteral("Encrypted and signed mail."));
     }
+
+    void testMalformedMessages_data()
+    {
+        QTest::addColumn<QByteArray>("data");
+
+        const auto files = QDir(QLatin1String(MAIL_DATA_DIR)).entryList({QStringLiteral("*.mbox")}, QDir::Files, QDir::Name);
+        QVERIFY(!files.isEmpty());
+        // Fixed seed so failures are reproducible
+        QRandomGenerator generator(42);
+        for (const auto &file : files) {
+            const auto mail = readMailFromFile(file);
+            for (int i = 1; i < 4; i++) {
+                QTest::newRow(qPrintable(file + QStringLiteral(" truncated %1/4").arg(i))) << mail.left(mail.size() * i / 4);
+            }
+            auto corrupted = mail;
+            for (int i = 0; i < corrupted.size() / 50; i++) {
+                corrupted[generator.bounded(corrupted.size())] = static_cast<char>(generator.bounded(256));
+            }
+            QTest::newRow(qPrintable(file + QStringLiteral(" corrupted"))) << corrupted;
+        }
+    }
+
+    void testMalformedMessages()
+    {
+        QFETCH(QByteArray, data);
+
+        // Nothing to compare against, parsing simply must not crash or hang.
+        // Runs with the test GNUPGHOME (see add_gpg_crypto_test), so corrupted
+        // PGP and S/MIME payloads go through decryption and verification too.
+        MimeTreeParser::ObjectTreeParser otp;
+        otp.parseObjectTree(data);
+        otp.decryptParts();
+        otp.plainTextContent();
+        otp.resolveCidLinks(otp.htmlContent());
+        const auto partList = otp.collectContentParts();
+        for (const auto &part : partList) {
+            part->text();
+        }
+        const auto attachments = otp.collectAttachmentParts();
+        for (const auto &attachment : attachments) {
+            attachment->filename();
+        }
+    }
 };
 
 QTEST_GUILESS_MAIN(MimeTreeParserTest)
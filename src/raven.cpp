@@ -10,6 +10,7 @@
 #include <QtCore/QItemSelectionModel>
 #include <QTimer>
 #include <QApplication>
+#include <QClipboard>
 
 // Akonadi
 #include <Akonadi/CollectionFilterProxyModel>
@@ -40,3 +41,8 @@ Raven *Raven::self()
     static Raven *instance = new Raven();
     return instance;
 }
+
+void Raven::copyToClipboard(const QString &text)
+{
+    QGuiApplication::clipboard()->setText(text);
+}
@@ -15,6 +15,8 @@ public:
     ~Raven() override = default;
     
     static Raven *self();
+
+    Q_INVOKABLE void copyToClipboard(const QString &text);
 };
 
 Q_GLOBAL_STATIC(Raven, raven)